@@ -98,6 +98,7 @@ mod tests {
             statements: vec![Box::new(LetStatement {
                 token: Token::Let,
                 name: Box::new(Identifier::new(&Token::Ident(String::from("myVar")))),
+                type_annotation: None,
                 value: Box::new(Identifier::new(&Token::Ident(String::from("anotherVar")))),
             })],
         };
@@ -0,0 +1,406 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+// Limbs are base 2^32, least significant first, with no trailing zero limbs.
+// Zero is an empty magnitude and is never negative.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: Vec<u32>,
+}
+
+const DECIMAL_CHUNK: u32 = 1_000_000_000;
+const DECIMAL_CHUNK_DIGITS: usize = 9;
+
+impl BigInt {
+    fn new(negative: bool, mut magnitude: Vec<u32>) -> BigInt {
+        while magnitude.last() == Some(&0) {
+            magnitude.pop();
+        }
+        let negative = negative && !magnitude.is_empty();
+
+        BigInt {
+            negative,
+            magnitude,
+        }
+    }
+
+    pub fn zero() -> BigInt {
+        BigInt::new(false, vec![])
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_empty()
+    }
+
+    // Parses an optionally signed string of decimal digits.
+    pub fn parse(input: &str) -> Option<BigInt> {
+        let (negative, digits) = match input.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, input),
+        };
+
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        let mut magnitude: Vec<u32> = vec![];
+        let first_chunk = digits.len() % DECIMAL_CHUNK_DIGITS;
+        let mut start = 0;
+
+        while start < digits.len() {
+            let end = if start == 0 && first_chunk != 0 {
+                first_chunk
+            } else {
+                start + DECIMAL_CHUNK_DIGITS
+            };
+            let chunk: u32 = digits[start..end].parse().ok()?;
+            let scale = 10u32.pow((end - start) as u32);
+
+            mul_small_add(&mut magnitude, scale, chunk);
+            start = end;
+        }
+
+        Some(BigInt::new(negative, magnitude))
+    }
+
+    pub fn to_i64(&self) -> Option<i64> {
+        if self.magnitude.len() > 2 {
+            return None;
+        }
+
+        let mut value: u64 = 0;
+        for (i, limb) in self.magnitude.iter().enumerate() {
+            value |= (*limb as u64) << (32 * i);
+        }
+
+        if self.negative {
+            if value <= i64::MAX as u64 + 1 {
+                return Some((value as i64).wrapping_neg());
+            }
+            None
+        } else {
+            i64::try_from(value).ok()
+        }
+    }
+
+    // The low 64 bits in two's complement, i.e. what i64 arithmetic would wrap to.
+    pub fn wrapping_to_i64(&self) -> i64 {
+        let mut value: u64 = 0;
+        for (i, limb) in self.magnitude.iter().take(2).enumerate() {
+            value |= (*limb as u64) << (32 * i);
+        }
+
+        if self.negative {
+            value.wrapping_neg() as i64
+        } else {
+            value as i64
+        }
+    }
+
+    pub fn neg(&self) -> BigInt {
+        BigInt::new(!self.negative, self.magnitude.clone())
+    }
+
+    pub fn add(&self, other: &BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::new(
+                self.negative,
+                add_magnitudes(&self.magnitude, &other.magnitude),
+            );
+        }
+
+        match compare_magnitudes(&self.magnitude, &other.magnitude) {
+            Ordering::Equal => BigInt::zero(),
+            Ordering::Greater => BigInt::new(
+                self.negative,
+                sub_magnitudes(&self.magnitude, &other.magnitude),
+            ),
+            Ordering::Less => BigInt::new(
+                other.negative,
+                sub_magnitudes(&other.magnitude, &self.magnitude),
+            ),
+        }
+    }
+
+    pub fn sub(&self, other: &BigInt) -> BigInt {
+        self.add(&other.neg())
+    }
+
+    pub fn mul(&self, other: &BigInt) -> BigInt {
+        BigInt::new(
+            self.negative != other.negative,
+            mul_magnitudes(&self.magnitude, &other.magnitude),
+        )
+    }
+
+    // Truncating division like i64: the quotient rounds towards zero and the
+    // remainder takes the sign of the dividend. None when dividing by zero.
+    pub fn div_rem(&self, other: &BigInt) -> Option<(BigInt, BigInt)> {
+        if other.is_zero() {
+            return None;
+        }
+
+        let (quotient, remainder) = div_rem_magnitudes(&self.magnitude, &other.magnitude);
+
+        Some((
+            BigInt::new(self.negative != other.negative, quotient),
+            BigInt::new(self.negative, remainder),
+        ))
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(value: i64) -> BigInt {
+        let abs = value.unsigned_abs();
+        BigInt::new(value < 0, vec![abs as u32, (abs >> 32) as u32])
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => compare_magnitudes(&self.magnitude, &other.magnitude),
+            (true, true) => compare_magnitudes(&other.magnitude, &self.magnitude),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        let mut chunks: Vec<u32> = vec![];
+        let mut magnitude = self.magnitude.clone();
+        while !magnitude.is_empty() {
+            chunks.push(div_rem_small(&mut magnitude, DECIMAL_CHUNK));
+        }
+
+        let mut out = String::new();
+        if self.negative {
+            out.push('-');
+        }
+        out.push_str(&chunks.pop().unwrap_or(0).to_string());
+        for chunk in chunks.iter().rev() {
+            out.push_str(&format!("{:09}", chunk));
+        }
+
+        write!(f, "{}", out)
+    }
+}
+
+fn compare_magnitudes(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_magnitudes(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut out = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = 0u64;
+
+    for i in 0..a.len().max(b.len()) {
+        let sum = *a.get(i).unwrap_or(&0) as u64 + *b.get(i).unwrap_or(&0) as u64 + carry;
+        out.push(sum as u32);
+        carry = sum >> 32;
+    }
+    if carry > 0 {
+        out.push(carry as u32);
+    }
+
+    out
+}
+
+// Requires a >= b.
+fn sub_magnitudes(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut out = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+
+    for (i, limb) in a.iter().enumerate() {
+        let mut difference = *limb as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+        if difference < 0 {
+            difference += 1 << 32;
+            borrow = 1;
+        } else {
+            borrow = 0;
+        }
+        out.push(difference as u32);
+    }
+
+    out
+}
+
+fn mul_magnitudes(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut out = vec![0u32; a.len() + b.len()];
+
+    for (i, x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, y) in b.iter().enumerate() {
+            let product = *x as u64 * *y as u64 + out[i + j] as u64 + carry;
+            out[i + j] = product as u32;
+            carry = product >> 32;
+        }
+        out[i + b.len()] = carry as u32;
+    }
+
+    out
+}
+
+fn mul_small_add(magnitude: &mut Vec<u32>, factor: u32, addend: u32) {
+    let mut carry = addend as u64;
+
+    for limb in magnitude.iter_mut() {
+        let product = *limb as u64 * factor as u64 + carry;
+        *limb = product as u32;
+        carry = product >> 32;
+    }
+    if carry > 0 {
+        magnitude.push(carry as u32);
+    }
+}
+
+// Divides in place and returns the remainder.
+fn div_rem_small(magnitude: &mut Vec<u32>, divisor: u32) -> u32 {
+    let mut remainder = 0u64;
+
+    for limb in magnitude.iter_mut().rev() {
+        let current = (remainder << 32) | *limb as u64;
+        *limb = (current / divisor as u64) as u32;
+        remainder = current % divisor as u64;
+    }
+    while magnitude.last() == Some(&0) {
+        magnitude.pop();
+    }
+
+    remainder as u32
+}
+
+// Binary long division; b must be non-zero.
+fn div_rem_magnitudes(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if compare_magnitudes(a, b) == Ordering::Less {
+        return (vec![], a.to_vec());
+    }
+
+    if b.len() == 1 {
+        let mut quotient = a.to_vec();
+        let remainder = div_rem_small(&mut quotient, b[0]);
+        return (quotient, vec![remainder]);
+    }
+
+    let mut quotient = vec![0u32; a.len()];
+    let mut remainder: Vec<u32> = vec![];
+
+    for bit in (0..a.len() * 32).rev() {
+        // remainder = remainder << 1 | next bit of a
+        let mut carry = (a[bit / 32] >> (bit % 32)) & 1;
+        for limb in remainder.iter_mut() {
+            let shifted_out = *limb >> 31;
+            *limb = (*limb << 1) | carry;
+            carry = shifted_out;
+        }
+        if carry > 0 {
+            remainder.push(carry);
+        }
+
+        if compare_magnitudes(&remainder, b) != Ordering::Less {
+            remainder = sub_magnitudes(&remainder, b);
+            while remainder.last() == Some(&0) {
+                remainder.pop();
+            }
+            quotient[bit / 32] |= 1 << (bit % 32);
+        }
+    }
+
+    (quotient, remainder)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bigint::BigInt;
+
+    fn big(input: &str) -> BigInt {
+        BigInt::parse(input).unwrap()
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let tests = vec![
+            "0",
+            "7",
+            "-7",
+            "4294967296",
+            "9223372036854775808",
+            "-9223372036854775809",
+            "1000000000000000000000000000000",
+            "123456789012345678901234567890123456789",
+        ];
+
+        for test in tests {
+            assert_eq!(big(test).to_string(), test);
+        }
+
+        assert_eq!(big("-0").to_string(), "0");
+        assert_eq!(big("000042").to_string(), "42");
+        assert_eq!(BigInt::parse(""), None);
+        assert_eq!(BigInt::parse("12a"), None);
+    }
+
+    #[test]
+    fn to_i64() {
+        assert_eq!(big("9223372036854775807").to_i64(), Some(i64::MAX));
+        assert_eq!(big("-9223372036854775808").to_i64(), Some(i64::MIN));
+        assert_eq!(big("9223372036854775808").to_i64(), None);
+        assert_eq!(BigInt::from(i64::MIN).to_string(), "-9223372036854775808");
+    }
+
+    #[test]
+    fn wrapping_to_i64() {
+        assert_eq!(big("9223372036854775808").wrapping_to_i64(), i64::MIN);
+        assert_eq!(big("-9223372036854775809").wrapping_to_i64(), i64::MAX);
+        assert_eq!(big("18446744073709551621").wrapping_to_i64(), 5);
+        assert_eq!(big("-42").wrapping_to_i64(), -42);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = big("123456789012345678901234567890");
+        let b = big("-987654321098765432109876543210");
+
+        assert_eq!(a.add(&b).to_string(), "-864197532086419753208641975320");
+        assert_eq!(a.sub(&b).to_string(), "1111111110111111111011111111100");
+        assert_eq!(
+            a.mul(&b).to_string(),
+            "-121932631137021795226185032733622923332237463801111263526900"
+        );
+
+        let (quotient, remainder) = b.div_rem(&a).unwrap();
+        assert_eq!(quotient.to_string(), "-8");
+        assert_eq!(remainder.to_string(), "-9000000000900000000090");
+
+        let (quotient, remainder) = a.mul(&a).div_rem(&big("-4294967297")).unwrap();
+        assert_eq!(
+            quotient.to_string(),
+            "-3548706590591494496889379123609238225941701553280"
+        );
+        assert_eq!(remainder.to_string(), "3315967940");
+
+        assert_eq!(a.div_rem(&BigInt::zero()), None);
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(big("-10000000000000000000000") < big("-1"));
+        assert!(big("-1") < big("0"));
+        assert!(big("10000000000000000000000") > big("9999999999999999999999"));
+    }
+}
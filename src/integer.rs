@@ -0,0 +1,396 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use crate::bigint::BigInt;
+
+// A Monke integer. Values that fit in an i64 are always stored as Small; when
+// i64 arithmetic overflows the result is promoted to Big, and Big results that
+// fit back into an i64 are demoted again. Equality and hashing go by value,
+// so a Big that holds a small value still equals the matching Small.
+#[derive(Debug, Clone)]
+pub enum Integer {
+    Small(i64),
+    Big(BigInt),
+}
+
+// What happens when a result does not fit in an i64. Promote keeps the exact
+// value as a BigInt; the others keep every value in i64 range.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum OverflowMode {
+    #[default]
+    Promote,
+    Wrap,
+    Saturate,
+    Error,
+}
+
+impl OverflowMode {
+    // Applies the mode to the exact result of an operation, e.g.
+    // `mode.apply(a.add(&b))`. Only Error mode fails.
+    pub fn apply(self, result: Integer) -> Result<Integer, String> {
+        let big = match result {
+            Integer::Small(_) => return Ok(result),
+            Integer::Big(big) => big,
+        };
+
+        match self {
+            OverflowMode::Promote => Ok(Integer::Big(big)),
+            OverflowMode::Wrap => Ok(Integer::Small(big.wrapping_to_i64())),
+            OverflowMode::Saturate if big < BigInt::zero() => Ok(Integer::Small(i64::MIN)),
+            OverflowMode::Saturate => Ok(Integer::Small(i64::MAX)),
+            OverflowMode::Error => {
+                Err(format!("integer overflow: {} does not fit in 64 bits", big))
+            }
+        }
+    }
+}
+
+impl Integer {
+    // Parses the digits of a Token::Int, promoting values that overflow i64.
+    pub fn parse(literal: &str) -> Option<Integer> {
+        match literal.parse::<i64>() {
+            Ok(value) => Some(Integer::Small(value)),
+            Err(_) => BigInt::parse(literal).map(Integer::from),
+        }
+    }
+
+    fn to_big(&self) -> BigInt {
+        match self {
+            Integer::Small(value) => BigInt::from(*value),
+            Integer::Big(value) => value.clone(),
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        match self {
+            Integer::Small(value) => *value == 0,
+            Integer::Big(value) => value.is_zero(),
+        }
+    }
+
+    pub fn neg(&self) -> Integer {
+        match self {
+            Integer::Small(value) => match value.checked_neg() {
+                Some(result) => Integer::Small(result),
+                None => Integer::from(BigInt::from(*value).neg()),
+            },
+            Integer::Big(value) => Integer::from(value.neg()),
+        }
+    }
+
+    pub fn add(&self, other: &Integer) -> Integer {
+        if let (Integer::Small(a), Integer::Small(b)) = (self, other) {
+            if let Some(result) = a.checked_add(*b) {
+                return Integer::Small(result);
+            }
+        }
+        Integer::from(self.to_big().add(&other.to_big()))
+    }
+
+    pub fn sub(&self, other: &Integer) -> Integer {
+        if let (Integer::Small(a), Integer::Small(b)) = (self, other) {
+            if let Some(result) = a.checked_sub(*b) {
+                return Integer::Small(result);
+            }
+        }
+        Integer::from(self.to_big().sub(&other.to_big()))
+    }
+
+    pub fn mul(&self, other: &Integer) -> Integer {
+        if let (Integer::Small(a), Integer::Small(b)) = (self, other) {
+            if let Some(result) = a.checked_mul(*b) {
+                return Integer::Small(result);
+            }
+        }
+        Integer::from(self.to_big().mul(&other.to_big()))
+    }
+
+    // Truncates towards zero like i64 division. None when dividing by zero.
+    pub fn div(&self, other: &Integer) -> Option<Integer> {
+        if other.is_zero() {
+            return None;
+        }
+        if let (Integer::Small(a), Integer::Small(b)) = (self, other) {
+            if let Some(result) = a.checked_div(*b) {
+                return Some(Integer::Small(result));
+            }
+        }
+        let (quotient, _) = self.to_big().div_rem(&other.to_big())?;
+        Some(Integer::from(quotient))
+    }
+
+    // The remainder takes the sign of the dividend. None when dividing by zero.
+    pub fn rem(&self, other: &Integer) -> Option<Integer> {
+        if other.is_zero() {
+            return None;
+        }
+        if let (Integer::Small(a), Integer::Small(b)) = (self, other) {
+            // only i64::MIN % -1 overflows, and its remainder is 0
+            return Some(Integer::Small(a.checked_rem(*b).unwrap_or(0)));
+        }
+        let (_, remainder) = self.to_big().div_rem(&other.to_big())?;
+        Some(Integer::from(remainder))
+    }
+}
+
+impl From<i64> for Integer {
+    fn from(value: i64) -> Integer {
+        Integer::Small(value)
+    }
+}
+
+impl From<BigInt> for Integer {
+    fn from(value: BigInt) -> Integer {
+        match value.to_i64() {
+            Some(small) => Integer::Small(small),
+            None => Integer::Big(value),
+        }
+    }
+}
+
+impl PartialEq for Integer {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Integer {}
+
+impl Hash for Integer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Integer::Small(value) => value.hash(state),
+            Integer::Big(value) => match value.to_i64() {
+                Some(small) => small.hash(state),
+                None => value.hash(state),
+            },
+        }
+    }
+}
+
+impl Ord for Integer {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Integer::Small(a), Integer::Small(b)) => a.cmp(b),
+            _ => self.to_big().cmp(&other.to_big()),
+        }
+    }
+}
+
+impl PartialOrd for Integer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Integer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Integer::Small(value) => write!(f, "{}", value),
+            Integer::Big(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    use crate::bigint::BigInt;
+    use crate::integer::{Integer, OverflowMode};
+
+    fn hash(integer: &Integer) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        integer.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn int(literal: &str) -> Integer {
+        Integer::parse(literal).unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(int("42"), Integer::Small(42));
+        assert_eq!(int("9223372036854775807"), Integer::Small(i64::MAX));
+
+        let big = int("9223372036854775808");
+        assert!(matches!(big, Integer::Big(_)));
+        assert_eq!(big.to_string(), "9223372036854775808");
+
+        assert_eq!(Integer::parse("abc"), None);
+    }
+
+    #[test]
+    fn promotes_on_overflow() {
+        let max = Integer::Small(i64::MAX);
+        let min = Integer::Small(i64::MIN);
+        let one = Integer::Small(1);
+
+        let tests = vec![
+            (max.add(&one), "9223372036854775808"),
+            (min.sub(&one), "-9223372036854775809"),
+            (max.mul(&max), "85070591730234615847396907784232501249"),
+            (min.neg(), "9223372036854775808"),
+            (min.div(&Integer::Small(-1)).unwrap(), "9223372036854775808"),
+        ];
+
+        for (result, expected) in tests {
+            assert!(matches!(result, Integer::Big(_)));
+            assert_eq!(result.to_string(), expected);
+        }
+
+        assert_eq!(min.rem(&Integer::Small(-1)), Some(Integer::Small(0)));
+    }
+
+    #[test]
+    fn demotes_when_result_fits() {
+        let big = int("9223372036854775808");
+
+        assert_eq!(big.sub(&Integer::Small(1)), Integer::Small(i64::MAX));
+        assert_eq!(big.div(&big), Some(Integer::Small(1)));
+        assert_eq!(big.rem(&Integer::Small(10)), Some(Integer::Small(8)));
+        assert_eq!(big.neg(), Integer::Small(i64::MIN));
+    }
+
+    #[test]
+    fn factorial() {
+        let mut result = Integer::Small(1);
+        for i in 1..=30 {
+            result = result.mul(&Integer::Small(i));
+        }
+
+        assert_eq!(result.to_string(), "265252859812191058636308480000000");
+        assert_eq!(
+            result.div(&int("265252859812191058636308480000")),
+            Some(Integer::Small(1000))
+        );
+    }
+
+    #[test]
+    fn division_by_zero() {
+        let big = int("99999999999999999999");
+
+        assert_eq!(Integer::Small(1).div(&Integer::Small(0)), None);
+        assert_eq!(big.rem(&Integer::Small(0)), None);
+    }
+
+    #[test]
+    fn comparison_across_representations() {
+        let big = int("9223372036854775808");
+        let negative_big = big.neg().sub(&Integer::Small(1));
+
+        assert!(big > Integer::Small(i64::MAX));
+        assert!(negative_big < Integer::Small(i64::MIN));
+        assert!(Integer::Small(0) > negative_big);
+        assert_eq!(big.sub(&Integer::Small(1)), Integer::Small(i64::MAX));
+    }
+
+    #[test]
+    fn equality_across_representations() {
+        let small = Integer::Small(5);
+        let big = Integer::Big(BigInt::from(5));
+
+        assert_eq!(big, small);
+        assert_eq!(hash(&big), hash(&small));
+        assert_ne!(big, Integer::Small(6));
+
+        let huge = int("9223372036854775808");
+        assert_eq!(
+            huge,
+            Integer::Big(BigInt::parse("9223372036854775808").unwrap())
+        );
+        assert_ne!(huge, Integer::Small(i64::MAX));
+    }
+
+    #[test]
+    fn overflow_modes() {
+        let max = Integer::Small(i64::MAX);
+        let min = Integer::Small(i64::MIN);
+        let one = Integer::Small(1);
+        let minus_one = Integer::Small(-1);
+        let two = Integer::Small(2);
+
+        // (operation, result, wrap, saturate)
+        let tests = vec![
+            ("MAX + 1", max.add(&one), i64::MIN, i64::MAX),
+            ("MIN + -1", min.add(&minus_one), i64::MAX, i64::MIN),
+            ("MIN - 1", min.sub(&one), i64::MAX, i64::MIN),
+            ("MAX - -1", max.sub(&minus_one), i64::MIN, i64::MAX),
+            ("MAX * 2", max.mul(&two), -2, i64::MAX),
+            ("MIN * 2", min.mul(&two), 0, i64::MIN),
+            ("MIN * -1", min.mul(&minus_one), i64::MIN, i64::MAX),
+            ("-MIN", min.neg(), i64::MIN, i64::MAX),
+            ("MIN / -1", min.div(&minus_one).unwrap(), i64::MIN, i64::MAX),
+        ];
+
+        for (operation, result, wrap, saturate) in tests {
+            assert!(
+                matches!(result, Integer::Big(_)),
+                "{} should overflow",
+                operation
+            );
+
+            assert_eq!(
+                OverflowMode::Promote.apply(result.clone()),
+                Ok(result.clone()),
+                "{} in promote mode",
+                operation
+            );
+            assert_eq!(
+                OverflowMode::Wrap.apply(result.clone()),
+                Ok(Integer::Small(wrap)),
+                "{} in wrap mode",
+                operation
+            );
+            assert_eq!(
+                OverflowMode::Saturate.apply(result.clone()),
+                Ok(Integer::Small(saturate)),
+                "{} in saturate mode",
+                operation
+            );
+            assert!(
+                OverflowMode::Error.apply(result).is_err(),
+                "{} in error mode",
+                operation
+            );
+        }
+    }
+
+    #[test]
+    fn overflow_modes_leave_small_results_alone() {
+        let modes = vec![
+            OverflowMode::Promote,
+            OverflowMode::Wrap,
+            OverflowMode::Saturate,
+            OverflowMode::Error,
+        ];
+
+        for mode in modes {
+            let max = Integer::Small(i64::MAX);
+
+            assert_eq!(
+                mode.apply(max.sub(&Integer::Small(1))),
+                Ok(Integer::Small(i64::MAX - 1))
+            );
+            assert_eq!(
+                mode.apply(Integer::Small(i64::MIN).div(&Integer::Small(1)).unwrap()),
+                Ok(Integer::Small(i64::MIN))
+            );
+            assert_eq!(
+                mode.apply(Integer::Small(i64::MIN).rem(&Integer::Small(-1)).unwrap()),
+                Ok(Integer::Small(0))
+            );
+        }
+
+        assert_eq!(OverflowMode::default(), OverflowMode::Promote);
+        assert_eq!(
+            OverflowMode::Error.apply(Integer::Small(i64::MAX).add(&Integer::Small(1))),
+            Err(String::from(
+                "integer overflow: 9223372036854775808 does not fit in 64 bits"
+            ))
+        );
+    }
+}
@@ -0,0 +1,25 @@
+use std::any::Any;
+
+use crate::expressions::Expression;
+use crate::integer::Integer;
+use crate::token::Literal;
+use crate::token::Token;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct IntegerLiteral {
+    pub token: Token,
+    pub value: Integer,
+}
+
+impl Expression for IntegerLiteral {
+    fn token_literal(&self) -> String {
+        self.token.literal()
+    }
+    fn expression_node(&self) {}
+    fn string(&self) -> String {
+        self.value.to_string()
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -0,0 +1,45 @@
+use std::any::Any;
+
+use crate::expressions::Expression;
+use crate::token::Literal;
+use crate::token::Token;
+use crate::utils::map_token_to_literal::escape_template_literal;
+
+#[derive(Debug)]
+pub enum InterpolatedPart {
+    Literal(String),
+    Expression(Box<dyn Expression>),
+}
+
+#[derive(Debug)]
+pub struct InterpolatedString {
+    pub token: Token,
+    pub parts: Vec<InterpolatedPart>,
+}
+
+impl Expression for InterpolatedString {
+    fn token_literal(&self) -> String {
+        self.token.literal()
+    }
+    fn expression_node(&self) {}
+    fn string(&self) -> String {
+        let mut out = String::from("`");
+
+        for part in &self.parts {
+            match part {
+                InterpolatedPart::Literal(literal) => {
+                    out.push_str(&escape_template_literal(literal))
+                }
+                InterpolatedPart::Expression(expression) => {
+                    out.push_str(&format!("${{{}}}", expression.string()))
+                }
+            }
+        }
+
+        out.push('`');
+        out
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
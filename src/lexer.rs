@@ -1,4 +1,4 @@
-use crate::token::Token;
+use crate::token::{TemplatePart, Token};
 
 pub struct Lexer {
     input: Vec<char>,
@@ -79,6 +79,144 @@ impl Lexer {
             .collect();
     }
 
+    fn read_string(&mut self) -> Token {
+        let mut string = String::new();
+
+        // skip the opening quote
+        self.read_char();
+
+        while self.ch != '"' {
+            match self.ch {
+                '\0' => return Token::Illegal,
+                '\\' => match self.read_escape() {
+                    Some(ch) => string.push(ch),
+                    None => {
+                        self.skip_to_closing('"');
+                        return Token::Illegal;
+                    }
+                },
+                _ => {
+                    string.push(self.ch);
+                    self.read_char();
+                }
+            }
+        }
+
+        Token::String(string)
+    }
+
+    // Reads the escape sequence starting at the current backslash. Only \n, \t,
+    // \\, \`, \" and \$ are known, anything else is None.
+    fn read_escape(&mut self) -> Option<char> {
+        // skip the backslash
+        self.read_char();
+
+        let ch = match self.ch {
+            'n' => '\n',
+            't' => '\t',
+            '\\' | '`' | '"' | '$' => self.ch,
+            _ => return None,
+        };
+        self.read_char();
+
+        Some(ch)
+    }
+
+    fn read_template(&mut self) -> Token {
+        let mut parts: Vec<TemplatePart> = Vec::new();
+        let mut literal = String::new();
+
+        // skip the opening backtick
+        self.read_char();
+
+        while self.ch != '`' {
+            match self.ch {
+                '\0' => return Token::Illegal,
+                '\\' => match self.read_escape() {
+                    Some(ch) => literal.push(ch),
+                    None => {
+                        self.skip_to_closing('`');
+                        return Token::Illegal;
+                    }
+                },
+                '$' if self.peek_char() == '{' => {
+                    if !literal.is_empty() {
+                        parts.push(TemplatePart::Literal(std::mem::take(&mut literal)));
+                    }
+
+                    // skip "${"
+                    self.read_char();
+                    self.read_char();
+
+                    match self.read_template_expression() {
+                        Some(tokens) => parts.push(TemplatePart::Expression(tokens)),
+                        None => {
+                            self.skip_to_closing('`');
+                            return Token::Illegal;
+                        }
+                    }
+                }
+                _ => {
+                    literal.push(self.ch);
+                    self.read_char();
+                }
+            }
+        }
+
+        if !literal.is_empty() {
+            parts.push(TemplatePart::Literal(literal));
+        }
+
+        Token::Template(parts)
+    }
+
+    // Lexes the tokens of an embedded expression up to the `}` closing the hole.
+    // Braces opened inside the expression (including nested templates) are tracked
+    // so that only the matching `}` ends it.
+    fn read_template_expression(&mut self) -> Option<Vec<Token>> {
+        let mut tokens: Vec<Token> = Vec::new();
+        let mut depth = 0;
+
+        loop {
+            let token = self.next_token();
+
+            match token {
+                Token::LBrace => depth += 1,
+                Token::RBrace if depth == 0 => return Some(tokens),
+                Token::RBrace => depth -= 1,
+                Token::Eof | Token::Illegal => return None,
+                _ => {}
+            }
+
+            tokens.push(token);
+        }
+    }
+
+    // Skips the rest of a malformed string or template up to its closing
+    // delimiter, so that its body is not lexed as code.
+    // Holes in a template are stepped over as code, so a backtick inside a
+    // nested template or string does not end the skipped template early.
+    fn skip_to_closing(&mut self, delimiter: char) {
+        while self.ch != delimiter && self.ch != '\0' {
+            match self.ch {
+                '\\' => {
+                    self.read_char();
+                    self.read_char();
+                }
+                '$' if delimiter == '`' && self.peek_char() == '{' => {
+                    // skip "${"
+                    self.read_char();
+                    self.read_char();
+
+                    // a malformed hole leaves the lexer past the bad token,
+                    // the rest of the template is skipped as usual
+                    self.read_template_expression();
+                }
+                _ => self.read_char(),
+            }
+        }
+    }
+
     fn skip_whitespace(&mut self) {
         while self.ch.is_ascii_whitespace() {
             self.read_char();
@@ -105,6 +243,10 @@ impl Lexer {
             '+' => token = Token::Plus,
             '{' => token = Token::LBrace,
             '}' => token = Token::RBrace,
+            '[' => token = Token::LBracket,
+            ']' => token = Token::RBracket,
+            ':' => token = Token::Colon,
+            '?' => token = Token::Question,
             '!' => {
                 if self.peek_char() == '=' {
                     self.read_char();
@@ -113,11 +255,20 @@ impl Lexer {
                     token = Token::Bang;
                 }
             }
-            '-' => token = Token::Minus,
+            '-' => {
+                if self.peek_char() == '>' {
+                    self.read_char();
+                    token = Token::Arrow;
+                } else {
+                    token = Token::Minus;
+                }
+            }
             '/' => token = Token::Slash,
             '*' => token = Token::Asterisk,
             '<' => token = Token::LessThan,
             '>' => token = Token::GreaterThan,
+            '"' => token = self.read_string(),
+            '`' => token = self.read_template(),
             '\0' => token = Token::Eof,
             _ => {
                 if self.ch.is_letter() {
@@ -131,12 +282,21 @@ impl Lexer {
                         "return" => Token::Return,
                         "true" => Token::True,
                         "false" => Token::False,
+                        "import" => Token::Import,
+                        "export" => Token::Export,
+                        "as" => Token::As,
+                        "try" => Token::Try,
+                        "catch" => Token::Catch,
+                        "finally" => Token::Finally,
+                        "throw" => Token::Throw,
                         _ => Token::Ident(ident),
                     };
                 } else if self.ch.is_ascii_digit() {
                     let digit = self.read_digit();
                     token = Token::Int(digit);
                 } else {
+                    // skip the illegal character so lexing can move on
+                    self.read_char();
                     token = Token::Illegal;
                 }
                 return token;
@@ -163,6 +323,7 @@ impl IsLetter for char {
 mod tests {
 
     use crate::lexer::*;
+    use crate::token::Literal;
 
     #[test]
     fn next_token() {
@@ -269,4 +430,220 @@ mod tests {
             assert_eq!(token, expected)
         })
     }
+
+    #[test]
+    fn template_literal() {
+        let input = "`Hello, ${name}! ${ `{${x}}` } \\${}`;";
+
+        let mut lexer = Lexer::new(input.to_string());
+
+        let token = lexer.next_token();
+        if let Token::Template(parts) = &token {
+            assert_eq!(parts.len(), 5);
+            assert_eq!(parts[0], TemplatePart::Literal("Hello, ".to_string()));
+
+            if let TemplatePart::Expression(tokens) = &parts[1] {
+                assert_eq!(tokens, &vec![Token::Ident("name".to_string())]);
+                assert_eq!(tokens[0].literal(), "name");
+            } else {
+                panic!("expected an expression part, got {:?}", parts[1]);
+            }
+
+            assert_eq!(parts[2], TemplatePart::Literal("! ".to_string()));
+
+            if let TemplatePart::Expression(tokens) = &parts[3] {
+                assert_eq!(tokens.len(), 1);
+                assert_eq!(tokens[0].literal(), "`{${x}}`");
+            } else {
+                panic!("expected an expression part, got {:?}", parts[3]);
+            }
+
+            assert_eq!(parts[4], TemplatePart::Literal(" ${}".to_string()));
+        } else {
+            panic!("expected a template token, got {:?}", token);
+        }
+
+        assert_eq!(token.literal(), "`Hello, ${name}! ${`{${x}}`} \\${}`");
+        assert_eq!(lexer.next_token(), Token::Semicolon);
+        assert_eq!(lexer.next_token(), Token::Eof);
+    }
+
+    #[test]
+    fn unterminated_template_literal() {
+        let mut lexer = Lexer::new("`Hello, ${name".to_string());
+        assert_eq!(lexer.next_token(), Token::Illegal);
+
+        let mut lexer = Lexer::new("`Hello".to_string());
+        assert_eq!(lexer.next_token(), Token::Illegal);
+    }
+
+    #[test]
+    fn template_literal_escapes() {
+        let mut lexer = Lexer::new("`line\\n\\ttab \\` \\\" \\$ \\\\`".to_string());
+
+        match lexer.next_token() {
+            Token::Template(parts) => assert_eq!(
+                parts,
+                vec![TemplatePart::Literal("line\n\ttab ` \" $ \\".to_string())]
+            ),
+            token => panic!("expected a template token, got {:?}", token),
+        }
+        assert_token(lexer.next_token(), Token::Eof);
+
+        // an unknown escape is illegal and skips the rest of the template
+        let mut lexer = Lexer::new("`a\\qb` rest".to_string());
+        assert_token(lexer.next_token(), Token::Illegal);
+        assert_token(lexer.next_token(), Token::Ident("rest".to_string()));
+
+        let mut lexer = Lexer::new("`a\\".to_string());
+        assert_token(lexer.next_token(), Token::Illegal);
+    }
+
+    #[test]
+    fn malformed_template_literal() {
+        // the rest of a template with a bad hole is skipped, not lexed as code
+        let mut lexer = Lexer::new("`a ${@} b` rest;".to_string());
+
+        assert_token(lexer.next_token(), Token::Illegal);
+        assert_token(lexer.next_token(), Token::Ident("rest".to_string()));
+        assert_token(lexer.next_token(), Token::Semicolon);
+        assert_token(lexer.next_token(), Token::Eof);
+
+        // backticks inside the holes of a skipped template don't end it
+        let mut lexer = Lexer::new("`a\\q ${ `x` } rest` z;".to_string());
+
+        assert_token(lexer.next_token(), Token::Illegal);
+        assert_token(lexer.next_token(), Token::Ident("z".to_string()));
+        assert_token(lexer.next_token(), Token::Semicolon);
+        assert_token(lexer.next_token(), Token::Eof);
+    }
+
+    #[test]
+    fn import_export() {
+        let input = r#"import "lib/math.mk" as math;
+        export let pi = 3;
+        "say \"hi\"";
+        "#;
+
+        let tests = vec![
+            Token::Import,
+            Token::String("lib/math.mk".to_string()),
+            Token::As,
+            Token::Ident("math".to_string()),
+            Token::Semicolon,
+            Token::Export,
+            Token::Let,
+            Token::Ident("pi".to_string()),
+            Token::Equal,
+            Token::Int("3".to_string()),
+            Token::Semicolon,
+            Token::String("say \"hi\"".to_string()),
+            Token::Semicolon,
+            Token::Eof,
+        ];
+
+        let mut lexer = Lexer::new(input.to_string());
+
+        tests.into_iter().for_each(|expected| {
+            let token = lexer.next_token();
+            assert_eq!(token, expected);
+            assert_eq!(token.literal(), expected.literal());
+        })
+    }
+
+    #[test]
+    fn try_catch_throw() {
+        let input = "try { throw err; } catch (e) { e; } finally { done; }";
+
+        let tests = vec![
+            Token::Try,
+            Token::LBrace,
+            Token::Throw,
+            Token::Ident("err".to_string()),
+            Token::Semicolon,
+            Token::RBrace,
+            Token::Catch,
+            Token::LParen,
+            Token::Ident("e".to_string()),
+            Token::RParen,
+            Token::LBrace,
+            Token::Ident("e".to_string()),
+            Token::Semicolon,
+            Token::RBrace,
+            Token::Finally,
+            Token::LBrace,
+            Token::Ident("done".to_string()),
+            Token::Semicolon,
+            Token::RBrace,
+            Token::Eof,
+        ];
+
+        let mut lexer = Lexer::new(input.to_string());
+
+        tests
+            .into_iter()
+            .for_each(|expected| assert_token(lexer.next_token(), expected))
+    }
+
+    #[test]
+    fn string_escapes() {
+        let mut lexer =
+            Lexer::new(r#""a\tb\n\"c\" \\ \` \$" "a\qb" rest "unterminated"#.to_string());
+
+        match lexer.next_token() {
+            Token::String(string) => assert_eq!(string, "a\tb\n\"c\" \\ ` $"),
+            token => panic!("expected a string token, got {:?}", token),
+        }
+
+        // an unknown escape is illegal and skips the rest of the string
+        assert_token(lexer.next_token(), Token::Illegal);
+        assert_token(lexer.next_token(), Token::Ident("rest".to_string()));
+        assert_token(lexer.next_token(), Token::Illegal);
+        assert_token(lexer.next_token(), Token::Eof);
+    }
+
+    #[test]
+    fn type_annotations() {
+        let input = "let f: fn([int], {string: bool}) -> int? = 5 - 1;";
+
+        let tests = vec![
+            Token::Let,
+            Token::Ident("f".to_string()),
+            Token::Colon,
+            Token::Function,
+            Token::LParen,
+            Token::LBracket,
+            Token::Ident("int".to_string()),
+            Token::RBracket,
+            Token::Comma,
+            Token::LBrace,
+            Token::Ident("string".to_string()),
+            Token::Colon,
+            Token::Ident("bool".to_string()),
+            Token::RBrace,
+            Token::RParen,
+            Token::Arrow,
+            Token::Ident("int".to_string()),
+            Token::Question,
+            Token::Equal,
+            Token::Int("5".to_string()),
+            Token::Minus,
+            Token::Int("1".to_string()),
+            Token::Semicolon,
+            Token::Eof,
+        ];
+
+        let mut lexer = Lexer::new(input.to_string());
+
+        tests
+            .into_iter()
+            .for_each(|expected| assert_token(lexer.next_token(), expected))
+    }
+
+    // Token's PartialEq only compares the variant, so also compare the literal
+    // to check the text carried by Ident, Int, String and Template tokens.
+    fn assert_token(token: Token, expected: Token) {
+        assert_eq!(token, expected);
+        assert_eq!(token.literal(), expected.literal());
+    }
 }
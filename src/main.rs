@@ -1,11 +1,16 @@
 pub mod ast;
+pub mod bigint;
 pub mod expressions;
 pub mod identifier;
+pub mod integer;
+pub mod integer_literal;
+pub mod interpolated_string;
 pub mod lexer;
 pub mod parser;
 pub mod repl;
 pub mod statements;
 pub mod token;
+pub mod type_expression;
 pub mod utils;
 
 fn main() {
@@ -4,13 +4,20 @@ use crate::{
     ast::Program,
     expressions::Expression,
     identifier::Identifier,
+    integer::Integer,
+    integer_literal::IntegerLiteral,
+    interpolated_string::{InterpolatedPart, InterpolatedString},
     lexer::Lexer,
-    statements::{ExpressionStatement, LetStatement, ReturnStatement, Statement},
-    token::Token,
+    statements::{
+        BlockStatement, CatchClause, ExportStatement, ExpressionStatement, ImportStatement,
+        LetStatement, ReturnStatement, Statement, ThrowStatement, TryStatement,
+    },
+    token::{Literal, TemplatePart, Token},
+    type_expression::TypeExpression,
 };
 
 pub struct Parser<'a> {
-    lexer: &'a mut Lexer,
+    tokens: Box<dyn Iterator<Item = Token> + 'a>,
     current_token: Token,
     peek_token: Token,
     errors: Vec<String>,
@@ -32,8 +39,14 @@ enum Precedence {
 
 impl<'a> Parser<'a> {
     pub fn new(lexer: &'a mut Lexer) -> Parser<'a> {
+        Parser::from_tokens(std::iter::from_fn(move || Some(lexer.next_token())))
+    }
+
+    // Parses tokens that were already lexed, e.g. the tokens of a template hole.
+    // Running out of tokens is the same as reaching Eof.
+    pub fn from_tokens(tokens: impl Iterator<Item = Token> + 'a) -> Parser<'a> {
         let mut parser = Parser {
-            lexer,
+            tokens: Box::new(tokens),
             current_token: Token::Illegal,
             peek_token: Token::Illegal,
             errors: vec![],
@@ -48,12 +61,15 @@ impl<'a> Parser<'a> {
         // register prefix parse functions
         // We are discarding the inner value of the Token::Ident variant, see token.rs impl Hash
         parser.register_prefix(Token::Ident(String::from("")), Parser::parse_identifier);
+        parser.register_prefix(Token::Int(String::from("")), Parser::parse_integer_literal);
+        parser.register_prefix(Token::Template(vec![]), Parser::parse_interpolated_string);
+        parser.register_prefix(Token::Illegal, Parser::parse_illegal);
         return parser;
     }
 
     fn next_token(&mut self) {
         self.current_token = self.peek_token.clone();
-        self.peek_token = self.lexer.next_token();
+        self.peek_token = self.tokens.next().unwrap_or(Token::Eof);
     }
 
     pub fn register_prefix(
@@ -70,6 +86,89 @@ impl<'a> Parser<'a> {
         return Some(Box::new(identifier));
     }
 
+    fn parse_integer_literal(&mut self) -> Option<Box<dyn Expression>> {
+        let literal = self.current_token.literal();
+
+        match Integer::parse(&literal) {
+            Some(value) => Some(Box::new(IntegerLiteral {
+                token: self.current_token.clone(),
+                value,
+            })),
+            None => {
+                self.errors
+                    .push(format!("could not parse {:?} as integer", literal));
+                None
+            }
+        }
+    }
+
+    fn parse_illegal(&mut self) -> Option<Box<dyn Expression>> {
+        self.errors.push(String::from(
+            "illegal token: unknown character or malformed string or template",
+        ));
+
+        None
+    }
+
+    fn parse_interpolated_string(&mut self) -> Option<Box<dyn Expression>> {
+        let template_parts = match &self.current_token {
+            Token::Template(parts) => parts.clone(),
+            _ => return None,
+        };
+
+        let mut parts: Vec<InterpolatedPart> = vec![];
+
+        for part in template_parts {
+            match part {
+                TemplatePart::Literal(literal) => parts.push(InterpolatedPart::Literal(literal)),
+                TemplatePart::Expression(tokens) => {
+                    let expression = self.parse_template_expression(tokens)?;
+                    parts.push(InterpolatedPart::Expression(expression));
+                }
+            }
+        }
+
+        Some(Box::new(InterpolatedString {
+            token: self.current_token.clone(),
+            parts,
+        }))
+    }
+
+    // Parses the tokens the lexer produced for a `${...}` hole with a nested parser.
+    fn parse_template_expression(&mut self, tokens: Vec<Token>) -> Option<Box<dyn Expression>> {
+        if tokens.is_empty() {
+            self.errors
+                .push(String::from("expected expression in template, got nothing"));
+            return None;
+        }
+
+        let mut parser = Parser::from_tokens(tokens.into_iter());
+
+        let expression = parser.parse_expression(Precedence::Lowest);
+        self.errors.append(&mut parser.errors);
+
+        let expression = match expression {
+            Some(expression) => expression,
+            None => {
+                self.errors.push(format!(
+                    "expected expression in template, got {:?} instead",
+                    parser.current_token
+                ));
+                return None;
+            }
+        };
+
+        if parser.peek_token != Token::Eof {
+            self.errors.push(format!(
+                "expected end of template expression, got {:?} instead",
+                parser.peek_token
+            ));
+            return None;
+        }
+
+        Some(expression)
+    }
+
     pub fn register_infix(
         &mut self,
         token: Token,
@@ -95,8 +194,16 @@ impl<'a> Parser<'a> {
 
     fn parse_statement(&mut self) -> Option<Box<dyn Statement>> {
         let val = match self.current_token {
-            Token::Let => return self.parse_let_statement(),
+            Token::Let => {
+                return self
+                    .parse_let_statement()
+                    .map(|statement| statement as Box<dyn Statement>)
+            }
             Token::Return => return self.parse_return_statement(),
+            Token::Import => return self.parse_import_statement(),
+            Token::Export => return self.parse_export_statement(),
+            Token::Throw => return self.parse_throw_statement(),
+            Token::Try => return self.parse_try_statement(),
             _ => self.parse_expression_statement(),
         };
         return val;
@@ -147,12 +254,13 @@ impl<'a> Parser<'a> {
         return left_exp;
     }
 
-    fn parse_let_statement(&mut self) -> Option<Box<dyn Statement>> {
+    fn parse_let_statement(&mut self) -> Option<Box<LetStatement>> {
         let illegal = Identifier::new(&Token::Illegal);
 
         let mut statement = LetStatement {
             token: self.current_token.clone(),
             name: Box::new(illegal.clone()),
+            type_annotation: None,
             value: Box::new(illegal),
         };
         //if the next token is an identifier
@@ -167,18 +275,88 @@ impl<'a> Parser<'a> {
         let statement_name = Identifier::new(&self.current_token);
         statement.name = Box::new(statement_name);
 
+        if self.peek_is(Token::Colon) {
+            self.next_token();
+            self.next_token();
+            statement.type_annotation = Some(self.parse_type_expression()?);
+        }
+
         if !self.expect_peek(Token::Equal) {
             return None;
         }
 
-        loop {
-            if self.current_token == Token::Semicolon {
-                break;
+        self.skip_unparsed_value();
+
+        return Some(Box::new(statement));
+    }
+
+    fn parse_type_expression(&mut self) -> Option<TypeExpression> {
+        let type_expression = match &self.current_token {
+            Token::Ident(name) => TypeExpression::Named(name.clone()),
+            Token::LBracket => {
+                self.next_token();
+                let element = self.parse_type_expression()?;
+
+                if !self.expect_peek(Token::RBracket) {
+                    return None;
+                }
+                TypeExpression::Array(Box::new(element))
+            }
+            Token::LBrace => {
+                self.next_token();
+                let key = self.parse_type_expression()?;
+
+                if !self.expect_peek(Token::Colon) {
+                    return None;
+                }
+                self.next_token();
+                let value = self.parse_type_expression()?;
+
+                if !self.expect_peek(Token::RBrace) {
+                    return None;
+                }
+                TypeExpression::Hash(Box::new(key), Box::new(value))
+            }
+            Token::Function => {
+                if !self.expect_peek(Token::LParen) {
+                    return None;
+                }
+
+                let mut parameters: Vec<TypeExpression> = vec![];
+                if !self.peek_is(Token::RParen) {
+                    self.next_token();
+                    parameters.push(self.parse_type_expression()?);
+
+                    while self.peek_is(Token::Comma) {
+                        self.next_token();
+                        self.next_token();
+                        parameters.push(self.parse_type_expression()?);
+                    }
+                }
+
+                if !self.expect_peek(Token::RParen) || !self.expect_peek(Token::Arrow) {
+                    return None;
+                }
+                self.next_token();
+                let result = self.parse_type_expression()?;
+
+                TypeExpression::Function(parameters, Box::new(result))
+            }
+            _ => {
+                self.errors.push(format!(
+                    "expected type, got {:?} instead",
+                    self.current_token
+                ));
+                return None;
             }
+        };
+
+        if self.peek_is(Token::Question) {
             self.next_token();
+            return Some(TypeExpression::Optional(Box::new(type_expression)));
         }
 
-        return Some(Box::new(statement));
+        Some(type_expression)
     }
 
     fn parse_return_statement(&mut self) -> Option<Box<dyn Statement>> {
@@ -187,11 +365,162 @@ impl<'a> Parser<'a> {
             return_value: Box::new(Identifier::new(&Token::Illegal)),
         };
 
-        if !self.current_is(Token::Semicolon) {
+        self.skip_unparsed_value();
+
+        return Some(Box::new(statement));
+    }
+
+    // Skips a statement value that is not parsed yet, up to and including its
+    // `;`. Without a semicolon it stops before a `}` or EOF, so that an
+    // enclosing block is left intact.
+    fn skip_unparsed_value(&mut self) {
+        while !self.peek_is(Token::Semicolon)
+            && !self.peek_is(Token::RBrace)
+            && !self.peek_is(Token::Eof)
+        {
             self.next_token();
         }
 
-        return Some(Box::new(statement));
+        if self.peek_is(Token::Semicolon) {
+            self.next_token();
+        }
+    }
+
+    fn parse_import_statement(&mut self) -> Option<Box<dyn Statement>> {
+        let token = self.current_token.clone();
+
+        if !self.expect_peek(Token::String(String::from(""))) {
+            return None;
+        }
+
+        let path = match &self.current_token {
+            Token::String(path) => path.clone(),
+            _ => return None,
+        };
+
+        if !self.expect_peek(Token::As) || !self.expect_peek(Token::Ident(String::from(""))) {
+            return None;
+        }
+
+        let alias = Identifier::new(&self.current_token);
+
+        if self.peek_is(Token::Semicolon) {
+            self.next_token();
+        }
+
+        Some(Box::new(ImportStatement {
+            token,
+            path,
+            alias: Box::new(alias),
+        }))
+    }
+
+    fn parse_export_statement(&mut self) -> Option<Box<dyn Statement>> {
+        let token = self.current_token.clone();
+
+        if !self.expect_peek(Token::Let) {
+            return None;
+        }
+
+        let statement = self.parse_let_statement()?;
+
+        Some(Box::new(ExportStatement { token, statement }))
+    }
+
+    fn parse_throw_statement(&mut self) -> Option<Box<dyn Statement>> {
+        let token = self.current_token.clone();
+
+        self.next_token();
+
+        let value = match self.parse_expression(Precedence::Lowest) {
+            Some(value) => value,
+            None => {
+                self.errors.push(format!(
+                    "expected expression after throw, got {:?} instead",
+                    self.current_token
+                ));
+                return None;
+            }
+        };
+
+        if self.peek_is(Token::Semicolon) {
+            self.next_token();
+        }
+
+        Some(Box::new(ThrowStatement { token, value }))
+    }
+
+    fn parse_try_statement(&mut self) -> Option<Box<dyn Statement>> {
+        let mut statement = TryStatement {
+            token: self.current_token.clone(),
+            block: Box::new(BlockStatement {
+                token: Token::LBrace,
+                statements: vec![],
+            }),
+            catch_clause: None,
+            finally_block: None,
+        };
+
+        if !self.expect_peek(Token::LBrace) {
+            return None;
+        }
+        statement.block = self.parse_block_statement()?;
+
+        if self.peek_is(Token::Catch) {
+            self.next_token();
+
+            if !self.expect_peek(Token::LParen) || !self.expect_peek(Token::Ident(String::new())) {
+                return None;
+            }
+            let parameter = Box::new(Identifier::new(&self.current_token));
+
+            if !self.expect_peek(Token::RParen) || !self.expect_peek(Token::LBrace) {
+                return None;
+            }
+            let block = self.parse_block_statement()?;
+            statement.catch_clause = Some(CatchClause { parameter, block });
+        }
+
+        if self.peek_is(Token::Finally) {
+            self.next_token();
+
+            if !self.expect_peek(Token::LBrace) {
+                return None;
+            }
+            statement.finally_block = Some(self.parse_block_statement()?);
+        }
+
+        if statement.catch_clause.is_none() && statement.finally_block.is_none() {
+            self.peek_error(Token::Catch);
+            return None;
+        }
+
+        Some(Box::new(statement))
+    }
+
+    fn parse_block_statement(&mut self) -> Option<Box<BlockStatement>> {
+        let mut block = BlockStatement {
+            token: self.current_token.clone(),
+            statements: vec![],
+        };
+
+        self.next_token();
+
+        while !self.current_is(Token::RBrace) {
+            if self.current_is(Token::Eof) {
+                self.errors.push(String::from(
+                    "expected RBrace to close block, got Eof instead",
+                ));
+                return None;
+            }
+
+            if let Some(statement) = self.parse_statement() {
+                block.statements.push(statement);
+            }
+            self.next_token();
+        }
+
+        Some(Box::new(block))
     }
 
     fn peek_is(&mut self, token: Token) -> bool {
@@ -226,9 +555,20 @@ impl<'a> Parser<'a> {
     }
 }
 
+#[cfg(test)]
 mod tests {
     use crate::{
-        identifier::Identifier, lexer::Lexer, parser::Parser, statements::ExpressionStatement,
+        expressions::Expression,
+        identifier::Identifier,
+        integer::Integer,
+        integer_literal::IntegerLiteral,
+        interpolated_string::{InterpolatedPart, InterpolatedString},
+        lexer::Lexer,
+        parser::Parser,
+        statements::{
+            ExportStatement, ExpressionStatement, ImportStatement, LetStatement, Statement,
+            ThrowStatement, TryStatement,
+        },
     };
 
     #[test]
@@ -265,6 +605,411 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_integer_literal_expression() {
+        let tests = vec![
+            ("5;", "5", Integer::Small(5)),
+            (
+                "9223372036854775807;",
+                "9223372036854775807",
+                Integer::Small(i64::MAX),
+            ),
+            (
+                "123456789012345678901234567890;",
+                "123456789012345678901234567890",
+                Integer::parse("123456789012345678901234567890").unwrap(),
+            ),
+        ];
+
+        for (input, expected_string, expected_value) in tests {
+            let mut l = Lexer::new(input.to_owned());
+            let mut p = Parser::new(&mut l);
+
+            let program = p.parse_program();
+
+            check_parser_errors(&p);
+
+            assert_eq!(program.statements.len(), 1);
+
+            let expression_statement = program.statements[0]
+                .as_any()
+                .downcast_ref::<ExpressionStatement>()
+                .expect("Statement is not ExpressionStatement");
+            let literal = expression_statement
+                .expression
+                .as_any()
+                .downcast_ref::<IntegerLiteral>()
+                .expect("Expression is not IntegerLiteral");
+
+            assert_eq!(literal.value, expected_value);
+            assert_eq!(literal.token_literal(), expected_string);
+            assert_eq!(literal.string(), expected_string);
+        }
+    }
+
+    #[test]
+    fn test_interpolated_string_expression() {
+        let input = "`Hello, ${name}!`;";
+        let mut l = Lexer::new(input.to_owned());
+        let mut p = Parser::new(&mut l);
+
+        let program = p.parse_program();
+
+        check_parser_errors(&p);
+
+        assert_eq!(program.statements.len(), 1);
+
+        let stmt = &program.statements[0];
+        let expression_statement = stmt
+            .as_any()
+            .downcast_ref::<ExpressionStatement>()
+            .expect("Statement is not ExpressionStatement");
+        let interpolated = expression_statement
+            .expression
+            .as_any()
+            .downcast_ref::<InterpolatedString>()
+            .expect("Expression is not InterpolatedString");
+
+        assert_eq!(interpolated.parts.len(), 3);
+        match &interpolated.parts[1] {
+            InterpolatedPart::Expression(expression) => {
+                let identifier = expression
+                    .as_any()
+                    .downcast_ref::<Identifier>()
+                    .expect("Expression is not Identifier");
+                assert_eq!(identifier.value, "name");
+            }
+            part => panic!("expected an expression part, got {:?}", part),
+        }
+        assert_eq!(interpolated.string(), "`Hello, ${name}!`");
+    }
+
+    #[test]
+    fn test_nested_interpolated_string() {
+        let input = "`a ${ `b ${x}` } c`;";
+        let mut l = Lexer::new(input.to_owned());
+        let mut p = Parser::new(&mut l);
+
+        let program = p.parse_program();
+
+        check_parser_errors(&p);
+        assert_eq!(program.string(), "`a ${`b ${x}`} c`");
+    }
+
+    #[test]
+    fn test_interpolated_string_round_trip() {
+        let input = "`a\\`b \\${x} \\\\ ${y}`";
+        let mut l = Lexer::new(input.to_owned());
+        let mut p = Parser::new(&mut l);
+
+        let program = p.parse_program();
+
+        check_parser_errors(&p);
+
+        let printed = program.string();
+        assert_eq!(printed, "`a\\`b \\${x} \\\\ ${y}`");
+
+        let mut l = Lexer::new(printed.clone());
+        let mut p = Parser::new(&mut l);
+
+        let reparsed = p.parse_program();
+
+        check_parser_errors(&p);
+        assert_eq!(reparsed.string(), printed);
+    }
+
+    #[test]
+    fn test_interpolated_string_errors() {
+        let tests = vec![
+            ("`${}`", "expected expression in template, got nothing"),
+            (
+                "`${a b}`",
+                "expected end of template expression, got Ident(\"b\") instead",
+            ),
+            (
+                "`${;}`",
+                "expected expression in template, got Semicolon instead",
+            ),
+            ("`${@}` rest;", "illegal token"),
+            ("`Hello, ${name", "illegal token"),
+            ("`Hello", "illegal token"),
+        ];
+
+        for (input, expected_error) in tests {
+            check_parser_fails(input, expected_error);
+        }
+    }
+
+    #[test]
+    fn test_malformed_template_does_not_swallow_input() {
+        let tests = vec![("`${@}` rest;", "rest"), ("`a\\q ${ `x` } rest` z;", "z")];
+
+        for (input, expected) in tests {
+            let mut l = Lexer::new(input.to_owned());
+            let mut p = Parser::new(&mut l);
+
+            let program = p.parse_program();
+
+            assert_eq!(p.errors.len(), 1, "{}: {:?}", input, p.errors);
+            assert_eq!(program.string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_import_export_statements() {
+        let input = "
+        import \"lib/math.mk\" as math;
+        export let pi = 3;
+        ";
+        let mut l = Lexer::new(input.to_owned());
+        let mut p = Parser::new(&mut l);
+
+        let program = p.parse_program();
+
+        check_parser_errors(&p);
+
+        assert_eq!(program.statements.len(), 2);
+
+        let import = program.statements[0]
+            .as_any()
+            .downcast_ref::<ImportStatement>()
+            .expect("Statement is not ImportStatement");
+        assert_eq!(import.path, "lib/math.mk");
+        assert_eq!(import.alias.value, "math");
+        assert_eq!(import.string(), "import \"lib/math.mk\" as math;");
+
+        let export = program.statements[1]
+            .as_any()
+            .downcast_ref::<ExportStatement>()
+            .expect("Statement is not ExportStatement");
+        assert_eq!(export.statement.name.value, "pi");
+    }
+
+    #[test]
+    fn test_import_statement_errors() {
+        let tests = vec![
+            (
+                "import math;",
+                "expected next token to be String(\"\"), got Ident(\"math\") instead",
+            ),
+            (
+                "import \"math.mk\" math;",
+                "expected next token to be As, got Ident(\"math\") instead",
+            ),
+            (
+                "import \"math.mk\" as;",
+                "expected next token to be Ident(\"\"), got Semicolon instead",
+            ),
+            (
+                "export pi;",
+                "expected next token to be Let, got Ident(\"pi\") instead",
+            ),
+            (
+                "import \"ma\\qth.mk\" as m;",
+                "expected next token to be String(\"\"), got Illegal instead",
+            ),
+        ];
+
+        for (input, expected_error) in tests {
+            check_parser_fails(input, expected_error);
+        }
+    }
+
+    #[test]
+    fn test_try_catch_finally_statement() {
+        let input = "try { throw err; } catch (e) { e; } finally { done; }";
+        let mut l = Lexer::new(input.to_owned());
+        let mut p = Parser::new(&mut l);
+
+        let program = p.parse_program();
+
+        check_parser_errors(&p);
+
+        assert_eq!(program.statements.len(), 1);
+
+        let statement = program.statements[0]
+            .as_any()
+            .downcast_ref::<TryStatement>()
+            .expect("Statement is not TryStatement");
+
+        assert_eq!(statement.block.statements.len(), 1);
+        let throw = statement.block.statements[0]
+            .as_any()
+            .downcast_ref::<ThrowStatement>()
+            .expect("Statement is not ThrowStatement");
+        assert_eq!(throw.value.string(), "err");
+
+        let catch_clause = statement.catch_clause.as_ref().unwrap();
+        assert_eq!(catch_clause.parameter.value, "e");
+        assert_eq!(catch_clause.block.statements.len(), 1);
+        assert_eq!(
+            statement.finally_block.as_ref().unwrap().statements.len(),
+            1
+        );
+        assert_eq!(
+            statement.string(),
+            "try { throw err; } catch (e) { e } finally { done }"
+        );
+    }
+
+    #[test]
+    fn test_return_statement_without_semicolon() {
+        let tests = vec![
+            (
+                "try { return 5 } catch (e) { e; } x;",
+                2,
+                "try { return ILLEGAL; } catch (e) { e }x",
+            ),
+            ("return add(5)", 1, "return ILLEGAL;"),
+            ("return; x", 2, "return ILLEGAL;x"),
+        ];
+
+        for (input, statements, expected) in tests {
+            let mut l = Lexer::new(input.to_owned());
+            let mut p = Parser::new(&mut l);
+
+            let program = p.parse_program();
+
+            check_parser_errors(&p);
+            assert_eq!(program.statements.len(), statements, "{}", input);
+            assert_eq!(program.string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_try_statement_errors() {
+        let tests = vec![
+            (
+                "try a;",
+                "expected next token to be LBrace, got Ident(\"a\") instead",
+            ),
+            (
+                "try { a; }",
+                "expected next token to be Catch, got Eof instead",
+            ),
+            (
+                "try { a; } catch { b; }",
+                "expected next token to be LParen, got LBrace instead",
+            ),
+            (
+                "try { a; } catch (e) b;",
+                "expected next token to be LBrace, got Ident(\"b\") instead",
+            ),
+            (
+                "try { a; } catch (e) { b;",
+                "expected RBrace to close block, got Eof instead",
+            ),
+            (
+                "throw;",
+                "expected expression after throw, got Semicolon instead",
+            ),
+        ];
+
+        for (input, expected_error) in tests {
+            check_parser_fails(input, expected_error);
+        }
+    }
+
+    #[test]
+    fn test_let_statement_type_annotations() {
+        let tests = vec![
+            ("let x = 5;", None),
+            ("let x: int = 5;", Some("int")),
+            ("let xs: [string] = 5;", Some("[string]")),
+            ("let h: {string: [int]} = 5;", Some("{string: [int]}")),
+            ("let f: fn() -> bool = 5;", Some("fn() -> bool")),
+            (
+                "let f: fn(int, string) -> [int?] = 5;",
+                Some("fn(int, string) -> [int?]"),
+            ),
+            ("let x: int? = 5;", Some("int?")),
+        ];
+
+        for (input, expected) in tests {
+            let mut l = Lexer::new(input.to_owned());
+            let mut p = Parser::new(&mut l);
+
+            let program = p.parse_program();
+
+            check_parser_errors(&p);
+
+            assert_eq!(program.statements.len(), 1);
+
+            let let_statement = program.statements[0]
+                .as_any()
+                .downcast_ref::<LetStatement>()
+                .expect("Statement is not LetStatement");
+
+            assert_eq!(
+                let_statement.type_annotation.as_ref().map(|t| t.string()),
+                expected.map(String::from)
+            );
+
+            let annotation = expected.map(|t| format!(": {}", t)).unwrap_or_default();
+            assert!(
+                let_statement
+                    .string()
+                    .starts_with(&format!("let {}{} =", let_statement.name.value, annotation)),
+                "unexpected string {}",
+                let_statement.string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_let_statement_without_semicolon() {
+        let tests = vec![
+            ("let x: int = 5", 1, "let x: int = ILLEGAL;"),
+            (
+                "try { let x = 5 } catch (e) { e; }",
+                1,
+                "try { let x = ILLEGAL; } catch (e) { e }",
+            ),
+        ];
+
+        for (input, statements, expected) in tests {
+            let mut l = Lexer::new(input.to_owned());
+            let mut p = Parser::new(&mut l);
+
+            let program = p.parse_program();
+
+            check_parser_errors(&p);
+            assert_eq!(program.statements.len(), statements, "{}", input);
+            assert_eq!(program.string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_let_statement_type_annotation_errors() {
+        let tests = vec![
+            ("let x: = 5;", "expected type, got Equal instead"),
+            (
+                "let x: [int = 5;",
+                "expected next token to be RBracket, got Equal instead",
+            ),
+            (
+                "let h: {string} = 5;",
+                "expected next token to be Colon, got RBrace instead",
+            ),
+            (
+                "let f: fn(int) = 5;",
+                "expected next token to be Arrow, got Equal instead",
+            ),
+            (
+                "let f: fn(int -> int) = 5;",
+                "expected next token to be RParen, got Arrow instead",
+            ),
+            (
+                "let x: int 5;",
+                "expected next token to be Equal, got Int(\"5\") instead",
+            ),
+        ];
+
+        for (input, expected_error) in tests {
+            check_parser_fails(input, expected_error);
+        }
+    }
+
     fn check_parser_errors(p: &Parser) {
         let errors = &p.errors;
 
@@ -272,4 +1017,24 @@ mod tests {
             panic!("Parser errors: {:?}", errors);
         }
     }
+
+    // Checks that parsing fails and that the first error is the expected one,
+    // so a test can't pass because parsing failed for some other reason.
+    fn check_parser_fails(input: &str, expected_error: &str) {
+        let mut l = Lexer::new(input.to_owned());
+        let mut p = Parser::new(&mut l);
+
+        p.parse_program();
+
+        match p.errors.first() {
+            Some(error) => assert!(
+                error.contains(expected_error),
+                "expected parser error containing {:?} for {}, got {:?}",
+                expected_error,
+                input,
+                p.errors
+            ),
+            None => panic!("expected parser errors for {}, got none", input),
+        }
+    }
 }
@@ -1,5 +1,5 @@
 use crate::{
-    expressions::Expression, identifier::Identifier, token::Token,
+    expressions::Expression, identifier::Identifier, token::Token, type_expression::TypeExpression,
     utils::map_token_to_literal::map_token_to_literal,
 };
 use std::any::Any;
@@ -25,6 +25,7 @@ pub struct Program {
 pub struct LetStatement {
     pub token: Token,
     pub name: Box<Identifier>,
+    pub type_annotation: Option<TypeExpression>,
     pub value: Box<dyn Expression>,
 }
 
@@ -37,10 +38,16 @@ impl Statement for LetStatement {
         self
     }
     fn string(&self) -> String {
+        let annotation = match &self.type_annotation {
+            Some(type_annotation) => format!(": {}", type_annotation.string()),
+            None => String::new(),
+        };
+
         format!(
-            "{} {} = {};",
+            "{} {}{} = {};",
             self.token_literal(),
             self.name.string(),
+            annotation,
             self.value.string()
         )
     }
@@ -89,3 +96,128 @@ impl ExpressionStatement {
         return ExpressionStatement { token, expression };
     }
 }
+
+#[derive(Debug)]
+pub struct ImportStatement {
+    pub token: Token,
+    pub path: String,
+    pub alias: Box<Identifier>,
+}
+
+impl Statement for ImportStatement {
+    fn statement_node(&self) {}
+    fn token_literal(&self) -> String {
+        map_token_to_literal(&self.token)
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn string(&self) -> String {
+        format!(
+            "{} {} as {};",
+            self.token_literal(),
+            map_token_to_literal(&Token::String(self.path.clone())),
+            self.alias.string()
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct ExportStatement {
+    pub token: Token,
+    pub statement: Box<LetStatement>,
+}
+
+impl Statement for ExportStatement {
+    fn statement_node(&self) {}
+    fn token_literal(&self) -> String {
+        map_token_to_literal(&self.token)
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn string(&self) -> String {
+        format!("{} {}", self.token_literal(), self.statement.string())
+    }
+}
+
+#[derive(Debug)]
+pub struct BlockStatement {
+    pub token: Token,
+    pub statements: Vec<Box<dyn Statement>>,
+}
+
+impl Statement for BlockStatement {
+    fn statement_node(&self) {}
+    fn token_literal(&self) -> String {
+        map_token_to_literal(&self.token)
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn string(&self) -> String {
+        let statements: Vec<String> = self.statements.iter().map(|s| s.string()).collect();
+        format!("{{ {} }}", statements.join(" "))
+    }
+}
+
+#[derive(Debug)]
+pub struct ThrowStatement {
+    pub token: Token,
+    pub value: Box<dyn Expression>,
+}
+
+impl Statement for ThrowStatement {
+    fn statement_node(&self) {}
+    fn token_literal(&self) -> String {
+        map_token_to_literal(&self.token)
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn string(&self) -> String {
+        format!("{} {};", self.token_literal(), self.value.string())
+    }
+}
+
+// At least one of the catch and finally clauses is present.
+#[derive(Debug)]
+pub struct TryStatement {
+    pub token: Token,
+    pub block: Box<BlockStatement>,
+    pub catch_clause: Option<CatchClause>,
+    pub finally_block: Option<Box<BlockStatement>>,
+}
+
+// The `catch (e) { ... }` part of a try statement.
+#[derive(Debug)]
+pub struct CatchClause {
+    pub parameter: Box<Identifier>,
+    pub block: Box<BlockStatement>,
+}
+
+impl Statement for TryStatement {
+    fn statement_node(&self) {}
+    fn token_literal(&self) -> String {
+        map_token_to_literal(&self.token)
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn string(&self) -> String {
+        let mut out = format!("{} {}", self.token_literal(), self.block.string());
+
+        if let Some(catch_clause) = &self.catch_clause {
+            out.push_str(&format!(
+                " catch ({}) {}",
+                catch_clause.parameter.string(),
+                catch_clause.block.string()
+            ));
+        }
+        if let Some(block) = &self.finally_block {
+            out.push_str(&format!(" finally {}", block.string()));
+        }
+
+        out
+    }
+}
@@ -6,6 +6,7 @@ use std::hash::{Hash, Hasher};
 pub enum Token {
     Ident(String),
     Int(String),
+    String(String),
     Illegal,
     Eof,
     Equal,
@@ -31,6 +32,26 @@ pub enum Token {
     False,
     EqualEqual,
     NotEqual,
+    Template(Vec<TemplatePart>),
+    Import,
+    Export,
+    As,
+    Try,
+    Catch,
+    Finally,
+    Throw,
+    Colon,
+    LBracket,
+    RBracket,
+    Arrow,
+    Question,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum TemplatePart {
+    Literal(String),
+    // Tokens of an embedded `${...}` expression, lexed by the same lexer
+    Expression(Vec<Token>),
 }
 
 pub trait Literal {
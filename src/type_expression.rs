@@ -0,0 +1,25 @@
+// A type annotation such as `int`, `[string]`, `{string: int}`,
+// `fn(int, int) -> bool` or `int?`.
+#[derive(Debug, PartialEq, Clone)]
+pub enum TypeExpression {
+    Named(String),
+    Array(Box<TypeExpression>),
+    Hash(Box<TypeExpression>, Box<TypeExpression>),
+    Function(Vec<TypeExpression>, Box<TypeExpression>),
+    Optional(Box<TypeExpression>),
+}
+
+impl TypeExpression {
+    pub fn string(&self) -> String {
+        match self {
+            TypeExpression::Named(name) => name.clone(),
+            TypeExpression::Array(element) => format!("[{}]", element.string()),
+            TypeExpression::Hash(key, value) => format!("{{{}: {}}}", key.string(), value.string()),
+            TypeExpression::Function(parameters, result) => {
+                let parameters: Vec<String> = parameters.iter().map(|p| p.string()).collect();
+                format!("fn({}) -> {}", parameters.join(", "), result.string())
+            }
+            TypeExpression::Optional(inner) => format!("{}?", inner.string()),
+        }
+    }
+}
@@ -1,9 +1,12 @@
-use crate::token::Token;
+use crate::token::{Literal, TemplatePart, Token};
 
 pub fn map_token_to_literal(token: &Token) -> String {
     match token {
         Token::Ident(ident) => ident.to_string(),
         Token::Int(int) => int.to_string(),
+        Token::String(string) => {
+            format!("\"{}\"", string.replace('\\', "\\\\").replace('"', "\\\""))
+        }
         Token::Equal => String::from("="),
         Token::Plus => String::from("+"),
         Token::Comma => String::from(","),
@@ -27,7 +30,51 @@ pub fn map_token_to_literal(token: &Token) -> String {
         Token::False => String::from("false"),
         Token::EqualEqual => String::from("=="),
         Token::NotEqual => String::from("!="),
+        Token::Template(parts) => map_template_to_literal(parts),
+        Token::Import => String::from("import"),
+        Token::Export => String::from("export"),
+        Token::As => String::from("as"),
+        Token::Try => String::from("try"),
+        Token::Catch => String::from("catch"),
+        Token::Finally => String::from("finally"),
+        Token::Throw => String::from("throw"),
+        Token::Colon => String::from(":"),
+        Token::LBracket => String::from("["),
+        Token::RBracket => String::from("]"),
+        Token::Arrow => String::from("->"),
+        Token::Question => String::from("?"),
         Token::Illegal => String::from("ILLEGAL"),
         Token::Eof => String::from("EOF"),
     }
 }
+
+fn map_template_to_literal(parts: &[TemplatePart]) -> String {
+    let mut out = String::from("`");
+
+    for part in parts {
+        match part {
+            TemplatePart::Literal(literal) => out.push_str(&escape_template_literal(literal)),
+            TemplatePart::Expression(tokens) => {
+                let expression: Vec<String> = tokens.iter().map(|token| token.literal()).collect();
+                out.push_str(&format!("${{{}}}", expression.join(" ")));
+            }
+        }
+    }
+
+    out.push('`');
+    out
+}
+
+// Escapes the literal text of a template so that it lexes back to the same text.
+pub fn escape_template_literal(literal: &str) -> String {
+    let mut out = String::new();
+
+    for ch in literal.chars() {
+        if matches!(ch, '`' | '$' | '\\') {
+            out.push('\\');
+        }
+        out.push(ch);
+    }
+
+    out
+}